@@ -0,0 +1,14 @@
+//! Utilities for waking tasks when a value is observed or changed.
+//!
+//! The crate is `no_std` and only pulls in `alloc` for the slab-backed waker
+//! registry and the reference-counted closure wakers, so the primitives here can be used under any executor —
+//! tokio, smol, an embedded runtime, or a hand-rolled one — without dragging
+//! in a particular async runtime.
+#![no_std]
+
+extern crate alloc;
+
+#[cfg(test)]
+extern crate std;
+
+pub mod wake_on;
@@ -1,6 +1,39 @@
 //! Smart pointers to wake tasks on access
-use async_std::task::Waker;
-use std::ops::{Deref, DerefMut};
+use alloc::sync::Arc;
+use core::future::Future;
+use core::ops::{Deref, DerefMut};
+use core::pin::Pin;
+use core::task::{Context, Poll, RawWaker, RawWakerVTable, Waker};
+use slab::Slab;
+
+/// The slab key reserved for the single-waker convenience API (`set_waker`,
+/// `take_waker`, `waker`). `new`/`Default` pre-occupy this slot with an empty
+/// registration so `register` never hands it out, letting the legacy API and
+/// `register` coexist.
+const LEGACY_KEY: usize = 0;
+
+/// An opaque handle identifying a `Waker` registered with a `WakeOnWrite`.
+///
+/// Returned by [`WakeOnWrite::register`] and passed back to
+/// [`WakeOnWrite::deregister`] when the observer is no longer interested in
+/// being woken.
+///
+/// The handle carries a generation alongside the slab index. `slab` recycles
+/// freed indices, so the generation distinguishes a live registration from a
+/// stale key left over after a previous occupant deregistered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct WakerKey {
+    index: usize,
+    generation: u64,
+}
+
+/// A single slot in a [`WakeOnWrite`]'s registry: a `Waker` (if one is
+/// registered) tagged with the generation of the handle that owns the slot.
+#[derive(Debug, Clone)]
+struct Registration {
+    generation: u64,
+    waker: Option<Waker>,
+}
 
 /// A wrapper type which wakes tasks whenever the wrapped value is accessed
 /// through an `&mut` reference.
@@ -20,37 +53,145 @@ use std::ops::{Deref, DerefMut};
 ///
 /// This type isn't effective for observing changes on values with interior
 /// mutablity, because it only wakes on `&mut` access.
-#[derive(Default, Debug, Clone)]
+///
+/// Multiple observers may watch the same value at once: each registers with
+/// [`register`](WakeOnWrite::register) and receives a [`WakerKey`], and every
+/// registered `Waker` is woken on `&mut` access. The single-waker API
+/// ([`set_waker`](WakeOnWrite::set_waker) and friends) is a convenience
+/// wrapper over a reserved key for the common one-observer case.
+#[derive(Debug, Clone)]
 pub struct WakeOnWrite<T> {
     inner: T,
-    waker: Option<Waker>,
+    wakers: Slab<Registration>,
+    next_generation: u64,
+}
+
+impl<T: Default> Default for WakeOnWrite<T> {
+    fn default() -> Self {
+        Self::new(T::default())
+    }
 }
 
 impl<T> WakeOnWrite<T> {
     /// Create a new `WakeOnWrite` with the given value.
     pub fn new(value: T) -> Self {
+        let mut wakers = Slab::new();
+        // Reserve the legacy slot so `register` never reuses it.
+        let legacy = wakers.insert(Registration {
+            generation: 0,
+            waker: None,
+        });
+        debug_assert_eq!(legacy, LEGACY_KEY);
         Self {
             inner: value,
-            waker: None,
+            wakers,
+            next_generation: 1,
+        }
+    }
+
+    /// Register a `Waker` to be woken when this value is mutated.
+    ///
+    /// Returns an opaque [`WakerKey`] identifying the registration, which can
+    /// be passed to [`deregister`](WakeOnWrite::deregister) when the observer
+    /// drops. Unlike [`set_waker`](WakeOnWrite::set_waker), registering does
+    /// not evict any previously registered `Waker`, so several futures can
+    /// observe the same value concurrently.
+    pub fn register(wow: &mut Self, waker: Waker) -> WakerKey {
+        let generation = wow.next_generation;
+        wow.next_generation = wow.next_generation.wrapping_add(1);
+        let index = wow.wakers.insert(Registration {
+            generation,
+            waker: Some(waker),
+        });
+        WakerKey { index, generation }
+    }
+
+    /// Remove a previously registered `Waker`, returning it if it was still
+    /// registered.
+    ///
+    /// A stale key whose slab index has since been reused by another observer
+    /// is rejected by the generation check and leaves that observer intact.
+    pub fn deregister(wow: &mut Self, key: WakerKey) -> Option<Waker> {
+        match wow.wakers.get(key.index) {
+            Some(reg) if reg.generation == key.generation => {
+                wow.wakers.remove(key.index).waker
+            }
+            _ => None,
         }
     }
 
     /// Set the `Waker` to be awoken when this value is mutated.
     ///
-    /// Returns the currently registered `Waker`, if there is one.
+    /// Returns the currently registered `Waker`, if there is one. This is a
+    /// convenience wrapper for the single-observer case, operating on the
+    /// reserved legacy slot so repeated calls replace rather than accumulate
+    /// and never disturb observers added via [`register`](Self::register).
     pub fn set_waker(wow: &mut Self, waker: Waker) -> Option<Waker> {
-        wow.waker.replace(waker)
+        wow.wakers[LEGACY_KEY].waker.replace(waker)
     }
 
     /// Removes and returns the currently registered `Waker`, if there is one.
     pub fn take_waker(wow: &mut Self) -> Option<Waker> {
-        wow.waker.take()
+        wow.wakers[LEGACY_KEY].waker.take()
     }
 
     /// Returns the currently registered `Waker`, leaving it registered, if
     /// there is one.
     pub fn waker(wow: &Self) -> Option<&Waker> {
-        wow.waker.as_ref()
+        wow.wakers[LEGACY_KEY].waker.as_ref()
+    }
+
+    /// Returns a future that completes once the wrapped value satisfies
+    /// `predicate`.
+    ///
+    /// `acquire` is called on every poll to obtain short-lived mutable access
+    /// to the `WakeOnWrite` — typically `|| mutex.lock()` or `|| cell
+    /// .borrow_mut()`. Each poll evaluates `predicate(&*inner)`; when it holds
+    /// the future resolves, otherwise it registers the polling task's `Waker`
+    /// and returns `Poll::Pending`. The guard is dropped at the end of each
+    /// poll, so a writer can take it and mutate the value between polls — were
+    /// the guard held for the future's lifetime no writer could ever change
+    /// the value and the predicate could never flip. This packages the
+    /// register-and-recheck dance that callers would otherwise hand-roll with
+    /// `poll_fn`.
+    pub fn wait_until<A, G, P>(acquire: A, predicate: P) -> WaitUntil<A, P>
+    where
+        A: FnMut() -> G,
+        G: DerefMut<Target = Self>,
+        P: FnMut(&T) -> bool,
+    {
+        WaitUntil { acquire, predicate }
+    }
+}
+
+/// The future returned by [`WakeOnWrite::wait_until`].
+///
+/// Modelled on the standard library's `PollFn`: it holds the guard-acquiring
+/// closure and the predicate, re-acquiring and releasing the guard on each
+/// poll rather than holding it across suspension.
+#[derive(Debug)]
+pub struct WaitUntil<A, P> {
+    acquire: A,
+    predicate: P,
+}
+
+impl<T, A, G, P> Future for WaitUntil<A, P>
+where
+    A: FnMut() -> G + Unpin,
+    G: DerefMut<Target = WakeOnWrite<T>>,
+    P: FnMut(&T) -> bool + Unpin,
+{
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<()> {
+        let this = self.get_mut();
+        let mut guard = (this.acquire)();
+        if (this.predicate)(&**guard) {
+            Poll::Ready(())
+        } else {
+            WakeOnWrite::set_waker(&mut guard, ctx.waker().clone());
+            Poll::Pending
+        }
     }
 }
 
@@ -63,55 +204,511 @@ impl<T> Deref for WakeOnWrite<T> {
 
 impl<T> DerefMut for WakeOnWrite<T> {
     fn deref_mut(&mut self) -> &mut Self::Target {
-        self.waker.as_ref().map(|w| w.wake_by_ref());
+        for (_, reg) in self.wakers.iter() {
+            if let Some(waker) = &reg.waker {
+                waker.wake_by_ref();
+            }
+        }
         &mut self.inner
     }
 }
 
-#[async_std::test]
-async fn wow_wakes_target_on_mut_access() {
-    use async_std::future::poll_fn;
-    use async_std::prelude::*;
-    use async_std::sync::Arc;
-    use async_std::sync::Mutex;
-    use async_std::task::Poll;
-    use pin_utils::pin_mut;
-    use std::future::Future;
-
-    let data: Arc<Mutex<WakeOnWrite<u8>>> = Default::default();
-    let data_checker = {
-        let data_ref = data.clone();
-        poll_fn(move |ctx| {
-            // This is an inefficient use of futures, but it does work in this
-            // case.
-            let data_lock_future = data_ref.lock();
-            pin_mut!(data_lock_future);
-            match data_lock_future.poll(ctx) {
-                Poll::Ready(mut lock) => match **lock {
-                    10 => Poll::Ready(()),
-                    _ => {
-                        WakeOnWrite::set_waker(&mut lock, ctx.waker().clone());
-                        Poll::Pending
+/// A lock-free single-slot cell holding at most one `Waker`.
+///
+/// This is the same state machine `futures-core` uses for its
+/// `AtomicWaker`: an `AtomicUsize` coordinates a producer registering a
+/// `Waker` against a consumer taking it, so neither side has to hold a lock
+/// on the wake path.
+struct AtomicWakerCell {
+    state: core::sync::atomic::AtomicUsize,
+    waker: core::cell::UnsafeCell<Option<Waker>>,
+}
+
+/// No observer is registering and no notification is pending.
+const WAITING: usize = 0;
+/// A `register` call is in progress and owns the `waker` cell.
+const REGISTERING: usize = 0b01;
+/// A notification arrived; the stored `Waker` is being (or has been) taken.
+const WOKEN: usize = 0b10;
+
+// The `UnsafeCell` is only ever accessed by the single thread that wins the
+// `WAITING -> REGISTERING` / `WAITING | WOKEN` transition, so sharing the cell
+// across threads is sound.
+unsafe impl Send for AtomicWakerCell {}
+unsafe impl Sync for AtomicWakerCell {}
+
+impl AtomicWakerCell {
+    const fn new() -> Self {
+        Self {
+            state: core::sync::atomic::AtomicUsize::new(WAITING),
+            waker: core::cell::UnsafeCell::new(None),
+        }
+    }
+
+    /// Register `waker` to be woken by the next `notify`.
+    ///
+    /// If a `notify` lands while the waker is being stored, the just-passed
+    /// waker is woken immediately instead of being held.
+    fn register(&self, waker: &Waker) {
+        use core::sync::atomic::Ordering::{AcqRel, Acquire};
+
+        match self
+            .state
+            .compare_exchange(WAITING, REGISTERING, Acquire, Acquire)
+            .unwrap_or_else(|state| state)
+        {
+            WAITING => {
+                unsafe {
+                    *self.waker.get() = Some(waker.clone());
+
+                    match self
+                        .state
+                        .compare_exchange(REGISTERING, WAITING, AcqRel, Acquire)
+                    {
+                        Ok(_) => {}
+                        Err(actual) => {
+                            // `notify` set the WOKEN bit while we were storing
+                            // the waker. Take it back and wake it ourselves.
+                            debug_assert_eq!(actual, REGISTERING | WOKEN);
+                            let waker = (*self.waker.get()).take().unwrap();
+                            self.state.swap(WAITING, AcqRel);
+                            waker.wake();
+                        }
                     }
-                },
-                Poll::Pending => Poll::Pending,
+                }
             }
-        })
-    };
-
-    let data_incrementor = {
-        let data_ref = data.clone();
-        async move {
-            for _ in 0..10u8 {
-                let mut lock = data_ref.lock().await;
-                **lock += 1;
+            // A concurrent `register`/`notify` is in flight; rather than
+            // contend, wake the new waker so it re-polls and re-registers.
+            _ => waker.wake_by_ref(),
+        }
+    }
+
+    /// Take the stored `Waker`, if any, marking the cell as woken.
+    fn take(&self) -> Option<Waker> {
+        use core::sync::atomic::Ordering::{AcqRel, Release};
+
+        match self.state.fetch_or(WOKEN, AcqRel) {
+            WAITING => {
+                let waker = unsafe { (*self.waker.get()).take() };
+                self.state.fetch_and(!WOKEN, Release);
+                waker
             }
+            _ => None,
+        }
+    }
+
+    /// Wake and clear the stored `Waker`, if one is registered.
+    fn notify(&self) {
+        if let Some(waker) = self.take() {
+            waker.wake();
+        }
+    }
+}
+
+impl core::fmt::Debug for AtomicWakerCell {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("AtomicWakerCell").finish_non_exhaustive()
+    }
+}
+
+impl Default for AtomicWakerCell {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A sibling of [`WakeOnWrite`] for values with interior mutability.
+///
+/// Where `WakeOnWrite` can only wake on `&mut` access, this type wraps the
+/// value behind a shared reference and relies on the caller to signal changes
+/// explicitly with [`notify`](AtomicWakeOnWrite::notify). A poller registers
+/// its `Waker` with [`register`](AtomicWakeOnWrite::register), mutates the
+/// value through interior mutability (for example a `RefCell` or an atomic),
+/// and then calls `notify` to wake the poller without taking a lock on the
+/// wake path.
+#[derive(Debug, Default)]
+pub struct AtomicWakeOnWrite<T> {
+    inner: T,
+    waker: AtomicWakerCell,
+}
+
+impl<T> AtomicWakeOnWrite<T> {
+    /// Create a new `AtomicWakeOnWrite` with the given value.
+    pub fn new(value: T) -> Self {
+        Self {
+            inner: value,
+            waker: AtomicWakerCell::new(),
         }
-    };
+    }
 
-    data_checker
-        .join(data_incrementor)
-        .timeout(core::time::Duration::new(1, 0))
-        .await
-        .unwrap();
+    /// Register the `Waker` to be woken by the next
+    /// [`notify`](AtomicWakeOnWrite::notify).
+    ///
+    /// Only the most recently registered `Waker` is retained. Like
+    /// [`WakeOnWrite`]'s API this is an associated function taking `this` by
+    /// shared reference, so it does not shadow methods on the wrapped `T`
+    /// reachable through `Deref` and can be called through an `Arc`.
+    pub fn register(this: &Self, waker: &Waker) {
+        this.waker.register(waker);
+    }
+
+    /// Wake the registered `Waker`, if there is one.
+    ///
+    /// Call this after mutating the wrapped value through interior mutability
+    /// so that the poller observing it makes progress.
+    pub fn notify(this: &Self) {
+        this.waker.notify();
+    }
+}
+
+impl<T> Deref for AtomicWakeOnWrite<T> {
+    type Target = T;
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+/// Build a `Waker` that invokes `callback` each time it is woken.
+///
+/// The closure is reference-counted behind an `Arc`, so cloning the returned
+/// `Waker` is cheap and the closure is dropped once the last clone goes away.
+/// This is handy for registering with [`WakeOnWrite::set_waker`] outside a
+/// real poll context — for example to re-arm an I/O or hardware callback on
+/// each wake. It is a convenience wrapper around [`waker_from_arc`].
+pub fn waker_fn<F>(callback: F) -> Waker
+where
+    F: Fn() + Send + Sync + 'static,
+{
+    waker_from_arc(Arc::new(callback))
+}
+
+/// Build a `Waker` from an already-shared callback.
+///
+/// Like [`waker_fn`], but takes the `Arc` directly so the caller can retain
+/// their own handle to the closure alongside the `Waker`.
+pub fn waker_from_arc<F>(callback: Arc<F>) -> Waker
+where
+    F: Fn() + Send + Sync + 'static,
+{
+    let data = Arc::into_raw(callback) as *const ();
+    unsafe { Waker::from_raw(RawWaker::new(data, waker_vtable::<F>())) }
+}
+
+fn waker_vtable<F>() -> &'static RawWakerVTable
+where
+    F: Fn() + Send + Sync + 'static,
+{
+    &RawWakerVTable::new(
+        clone_fn_raw::<F>,
+        wake_fn_raw::<F>,
+        wake_by_ref_fn_raw::<F>,
+        drop_fn_raw::<F>,
+    )
+}
+
+unsafe fn clone_fn_raw<F>(data: *const ()) -> RawWaker
+where
+    F: Fn() + Send + Sync + 'static,
+{
+    let arc = Arc::from_raw(data as *const F);
+    core::mem::forget(Arc::clone(&arc));
+    core::mem::forget(arc);
+    RawWaker::new(data, waker_vtable::<F>())
+}
+
+unsafe fn wake_fn_raw<F>(data: *const ())
+where
+    F: Fn() + Send + Sync + 'static,
+{
+    let arc = Arc::from_raw(data as *const F);
+    (arc)();
+}
+
+unsafe fn wake_by_ref_fn_raw<F>(data: *const ())
+where
+    F: Fn() + Send + Sync + 'static,
+{
+    let arc = Arc::from_raw(data as *const F);
+    (arc)();
+    core::mem::forget(arc);
+}
+
+unsafe fn drop_fn_raw<F>(data: *const ())
+where
+    F: Fn() + Send + Sync + 'static,
+{
+    drop(Arc::from_raw(data as *const F));
+}
+
+/// Deterministic helpers for testing code that registers `Waker`s.
+///
+/// Exercising a [`WakeOnWrite`] otherwise means spinning a real executor with
+/// a timeout, which is slow and racy. [`MockTask`] instead builds a `Waker`
+/// that flips a shared flag, letting a test poll a future by hand and assert
+/// exactly when a wake fired.
+///
+/// This module is opt-in behind the `testing` feature so dependents don't
+/// carry the mock harness in production builds; it is also available when
+/// running this crate's own tests.
+#[cfg(any(feature = "testing", test))]
+pub mod testing {
+    use super::waker_from_arc;
+    use alloc::boxed::Box;
+    use alloc::sync::Arc;
+    use core::future::Future;
+    use core::pin::Pin;
+    use core::sync::atomic::{AtomicBool, Ordering};
+    use core::task::{Context, Poll, Waker};
+
+    /// The boxed callback behind the mock `Waker`. Every clone of the waker
+    /// reference-counts this one allocation, so [`MockTask::waker_ref_count`]
+    /// observes registrations coming and going.
+    type Callback = Arc<Box<dyn Fn() + Send + Sync>>;
+
+    /// Shared state behind the mock `Waker`; the callback flips this flag.
+    #[derive(Debug)]
+    struct Shared {
+        woken: AtomicBool,
+    }
+
+    /// A stand-in for a spawned task that records whether it has been woken.
+    pub struct MockTask {
+        shared: Arc<Shared>,
+        callback: Callback,
+        waker: Waker,
+    }
+
+    /// Create a fresh [`MockTask`] with no pending wake.
+    pub fn spawn() -> MockTask {
+        MockTask::new()
+    }
+
+    impl MockTask {
+        /// Create a fresh `MockTask` with no pending wake.
+        pub fn new() -> Self {
+            let shared = Arc::new(Shared {
+                woken: AtomicBool::new(false),
+            });
+            // Build the waker out of the crate's own closure-to-`Waker`
+            // helper rather than a bespoke vtable: the callback just flips
+            // the shared flag.
+            let flag = Arc::clone(&shared);
+            let callback: Callback =
+                Arc::new(Box::new(move || flag.woken.store(true, Ordering::Release)));
+            let waker = waker_from_arc(Arc::clone(&callback));
+            Self {
+                shared,
+                callback,
+                waker,
+            }
+        }
+
+        /// The `Waker` backing this task, for registering with a
+        /// [`WakeOnWrite`](super::WakeOnWrite).
+        pub fn waker(&self) -> &Waker {
+            &self.waker
+        }
+
+        /// Report whether a wake fired since the last call, clearing the flag.
+        pub fn is_woken(&self) -> bool {
+            self.shared.woken.swap(false, Ordering::AcqRel)
+        }
+
+        /// Drive `future` a single time with this task's `Waker`.
+        pub fn poll<F: Future>(&self, future: Pin<&mut F>) -> Poll<F::Output> {
+            let mut ctx = Context::from_waker(&self.waker);
+            future.poll(&mut ctx)
+        }
+
+        /// The number of live references to the wake callback.
+        ///
+        /// Each registered `Waker` clone holds one reference; comparing
+        /// against the baseline lets a test detect registrations that were
+        /// never cleaned up.
+        pub fn waker_ref_count(&self) -> usize {
+            Arc::strong_count(&self.callback)
+        }
+    }
+
+    impl Default for MockTask {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn set_waker_wakes_on_mut_access() {
+        let task = testing::spawn();
+        let mut wow = WakeOnWrite::new(0u8);
+
+        let base = task.waker_ref_count();
+        WakeOnWrite::set_waker(&mut wow, task.waker().clone());
+        assert_eq!(task.waker_ref_count(), base + 1);
+        assert!(!task.is_woken());
+
+        *wow += 1;
+        assert!(task.is_woken());
+        // Consuming the wake clears the flag.
+        assert!(!task.is_woken());
+
+        // Taking the waker releases the registered clone.
+        let _ = WakeOnWrite::take_waker(&mut wow);
+        assert_eq!(task.waker_ref_count(), base);
+    }
+
+    #[test]
+    fn register_wakes_every_observer() {
+        let first = testing::spawn();
+        let second = testing::spawn();
+        let mut wow = WakeOnWrite::new(0u8);
+
+        let _first = WakeOnWrite::register(&mut wow, first.waker().clone());
+        let _second = WakeOnWrite::register(&mut wow, second.waker().clone());
+
+        *wow += 1;
+        assert!(first.is_woken());
+        assert!(second.is_woken());
+    }
+
+    #[test]
+    fn set_waker_does_not_evict_registered_observers() {
+        let legacy = testing::spawn();
+        let observer = testing::spawn();
+        let mut wow = WakeOnWrite::new(0u8);
+
+        let _observer = WakeOnWrite::register(&mut wow, observer.waker().clone());
+        WakeOnWrite::set_waker(&mut wow, legacy.waker().clone());
+
+        *wow += 1;
+        assert!(legacy.is_woken());
+        assert!(observer.is_woken());
+    }
+
+    #[test]
+    fn stale_key_does_not_deregister_reused_slot() {
+        let first = testing::spawn();
+        let second = testing::spawn();
+        let mut wow = WakeOnWrite::new(0u8);
+
+        let stale = WakeOnWrite::register(&mut wow, first.waker().clone());
+        assert!(WakeOnWrite::deregister(&mut wow, stale).is_some());
+
+        // The second observer reuses the freed slab index.
+        let _second = WakeOnWrite::register(&mut wow, second.waker().clone());
+        // The stale key must be rejected by the generation check.
+        assert!(WakeOnWrite::deregister(&mut wow, stale).is_none());
+
+        *wow += 1;
+        assert!(second.is_woken());
+    }
+
+    #[test]
+    fn wait_until_completes_when_predicate_flips() {
+        use core::cell::RefCell;
+        use core::pin::pin;
+
+        let cell = RefCell::new(WakeOnWrite::new(0u8));
+        let task = testing::spawn();
+        let fut = WakeOnWrite::wait_until(|| cell.borrow_mut(), |value: &u8| *value == 2);
+        let mut fut = pin!(fut);
+
+        assert!(task.poll(fut.as_mut()).is_pending());
+        assert!(!task.is_woken());
+
+        // A mutation that does not satisfy the predicate still wakes us.
+        **cell.borrow_mut() += 1;
+        assert!(task.is_woken());
+        assert!(task.poll(fut.as_mut()).is_pending());
+
+        // Reaching the target value resolves the future.
+        **cell.borrow_mut() += 1;
+        assert!(task.poll(fut.as_mut()).is_ready());
+    }
+
+    #[test]
+    fn atomic_wake_on_write_notifies_registered_task() {
+        use core::cell::Cell;
+
+        let task = testing::spawn();
+        let awow = AtomicWakeOnWrite::new(Cell::new(0u8));
+
+        AtomicWakeOnWrite::register(&awow, task.waker());
+        assert!(!task.is_woken());
+
+        // Mutate through interior mutability, then signal explicitly.
+        awow.set(awow.get() + 1);
+        AtomicWakeOnWrite::notify(&awow);
+        assert!(task.is_woken());
+    }
+
+    #[test]
+    fn waker_fn_invokes_callback_on_wake() {
+        use alloc::sync::Arc;
+        use core::sync::atomic::{AtomicUsize, Ordering};
+
+        let count = Arc::new(AtomicUsize::new(0));
+        let callback_count = Arc::clone(&count);
+        let waker = waker_fn(move || {
+            callback_count.fetch_add(1, Ordering::SeqCst);
+        });
+
+        waker.wake_by_ref();
+        // A clone shares the same callback and fires it independently.
+        let clone = waker.clone();
+        clone.wake();
+        assert_eq!(count.load(Ordering::SeqCst), 2);
+    }
+
+    #[async_std::test]
+    async fn wow_wakes_target_on_mut_access() {
+        use async_std::future::poll_fn;
+        use async_std::prelude::*;
+        use async_std::sync::Arc;
+        use async_std::sync::Mutex;
+        use async_std::task::Poll;
+        use pin_utils::pin_mut;
+        use std::future::Future;
+
+        let data: Arc<Mutex<WakeOnWrite<u8>>> = Default::default();
+        let data_checker = {
+            let data_ref = data.clone();
+            poll_fn(move |ctx| {
+                // This is an inefficient use of futures, but it does work in this
+                // case.
+                let data_lock_future = data_ref.lock();
+                pin_mut!(data_lock_future);
+                match data_lock_future.poll(ctx) {
+                    Poll::Ready(mut lock) => match **lock {
+                        10 => Poll::Ready(()),
+                        _ => {
+                            WakeOnWrite::set_waker(&mut lock, ctx.waker().clone());
+                            Poll::Pending
+                        }
+                    },
+                    Poll::Pending => Poll::Pending,
+                }
+            })
+        };
+
+        let data_incrementor = {
+            let data_ref = data.clone();
+            async move {
+                for _ in 0..10u8 {
+                    let mut lock = data_ref.lock().await;
+                    **lock += 1;
+                }
+            }
+        };
+
+        data_checker
+            .join(data_incrementor)
+            .timeout(core::time::Duration::new(1, 0))
+            .await
+            .unwrap();
+    }
 }